@@ -1,3 +1,88 @@
+#[cfg(not(feature = "no_std"))]
+const fn __rt_init_str_eq(a: &str, b: &str) -> bool {
+    let a = a.as_bytes();
+    let b = b.as_bytes();
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+#[cfg(not(feature = "no_std"))]
+const fn __rt_init_name_index(names: &[&str], target: &str) -> Option<usize> {
+    let mut i = 0;
+    while i < names.len() {
+        if __rt_init_str_eq(names[i], target) {
+            return Some(i);
+        }
+        i += 1;
+    }
+    None
+}
+
+// DFS with a tri-color `state` (0 = unvisited, 1 = visiting, 2 = done): revisiting a
+// `1` node means its own dependency chain loops back to it.
+#[cfg(not(feature = "no_std"))]
+const fn __rt_init_visit(idx: usize, names: &[&str], deps: &[&[&str]], state: &mut [u8]) -> bool {
+    if state[idx] == 1 {
+        return true;
+    }
+    if state[idx] == 2 {
+        return false;
+    }
+    state[idx] = 1;
+    let my_deps = deps[idx];
+    let mut i = 0;
+    while i < my_deps.len() {
+        if let Some(dep_idx) = __rt_init_name_index(names, my_deps[i]) {
+            if __rt_init_visit(dep_idx, names, deps, state) {
+                return true;
+            }
+        }
+        i += 1;
+    }
+    state[idx] = 2;
+    false
+}
+
+/// Used by `rt_init!`'s generated `const _: () = assert!(...)` check to reject cyclic
+/// `after(...)` dependencies at compile time. Not part of the public API.
+#[cfg(not(feature = "no_std"))]
+#[doc(hidden)]
+pub const fn __rt_init_has_after_cycle(names: &[&str], deps: &[&[&str]]) -> bool {
+    let mut any_deps = false;
+    let mut j = 0;
+    while j < deps.len() {
+        if !deps[j].is_empty() {
+            any_deps = true;
+        }
+        j += 1;
+    }
+    if !any_deps {
+        return false;
+    }
+    assert!(
+        names.len() <= 64,
+        "rt_init!: too many statics in one block that uses `after(...)` dependencies (max 64)"
+    );
+    let mut state = [0u8; 64];
+    let mut i = 0;
+    while i < names.len() {
+        if state[i] == 0 && __rt_init_visit(i, names, deps, &mut state) {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
+
 /// A macro to simplify the initialization of static variables using `spin::Lazy`.
 ///
 /// # Overview
@@ -10,17 +95,35 @@
 ///
 /// ```ignore
 /// rt_init! {
-///     [visibility] static NAME: TYPE = INITIALIZER;
+///     [preload(GROUP);]
+///     [#[attribute]]*
+///     [visibility] static NAME [after(DEP, ...)]: TYPE = INITIALIZER;
 ///     ...
 /// }
 /// ```
 ///
+/// - `[preload(GROUP);]`: Optional. When present, generates a `fn preload_GROUP()` that
+///   forces every static declared in the block, in declaration order. Call it at program
+///   start to pay initialization cost up front instead of on first access. The generated
+///   function takes the visibility of the block's first static, so a block of `pub`
+///   statics gets a `pub fn preload_GROUP()` callable from outside the defining module.
+/// - `[#[attribute]]*`: Any number of attributes, such as `#[cfg(...)]`, `#[allow(...)]`,
+///   or doc comments (`///`). They are re-emitted on the generated static, so they behave
+///   exactly as they would on a hand-written `static` item.
 /// - `[visibility]`: Optional visibility modifier, such as `pub` or `pub(crate)`.
 /// - `NAME`: The name of the static variable.
+/// - `[after(DEP, ...)]`: Optional. A list of other statics in the same block that must
+///   be forced before this one's initializer runs, preventing the deadlock that can occur
+///   when one static's initializer reads another `spin::Lazy` static concurrently.
 /// - `TYPE`: The type of the static variable.
 /// - `INITIALIZER`: An expression that initializes the static variable. It will be
 ///   evaluated the first time the variable is accessed.
 ///
+/// A block may also declare every static as `mut` (e.g. `rt_init! { mut static NAME: TYPE
+/// = INITIALIZER; }`) to get a read/write static instead of a read-only one; see
+/// "Mutable statics" below. A single `rt_init!` block is either all `mut` or all
+/// non-`mut` — split mutable and immutable statics into separate blocks.
+///
 /// # Examples
 ///
 /// Basic usage with different types:
@@ -59,20 +162,188 @@
 /// }
 /// ```
 ///
+/// Attaching attributes and doc comments:
+///
+/// ```rust
+/// use rt_init::rt_init;
+///
+/// rt_init! {
+///     /// The number of widgets currently in flight.
+///     #[cfg(not(test))]
+///     pub static WIDGET_COUNT: u64 = 0;
+///
+///     #[allow(dead_code)]
+///     static INTERNAL_CACHE: Vec<u64> = Vec::new();
+/// }
+/// ```
+///
+/// Eagerly preloading a group of statics:
+///
+/// ```rust
+/// use rt_init::rt_init;
+///
+/// rt_init! {
+///     preload(startup);
+///     static STATIC1: Vec<u64> = vec![1, 2, 3];
+///     static STATIC2: u64 = 42;
+/// }
+///
+/// fn main() {
+///     // Forces STATIC1 and STATIC2 now, instead of on first access.
+///     preload_startup();
+/// }
+/// ```
+///
+/// Declaring dependencies between statics to avoid deadlocks:
+///
+/// ```rust
+/// use rt_init::rt_init;
+///
+/// rt_init! {
+///     static CONFIG: u64 = 10;
+///     // DERIVED forces CONFIG before running its own initializer, so two threads
+///     // racing on CONFIG and DERIVED at the same time can never deadlock.
+///     static DERIVED after(CONFIG): u64 = *CONFIG * 2;
+/// }
+/// ```
+///
+/// Mutable statics with a reset API:
+///
+/// ```rust
+/// use rt_init::rt_init;
+///
+/// rt_init! {
+///     mut static CACHE: Vec<u64> = vec![1, 2, 3];
+/// }
+///
+/// fn main() {
+///     CACHE.write().push(4);
+///     assert_eq!(CACHE.read().len(), 4);
+///
+///     // Re-runs the initializer, discarding any mutations.
+///     reset_CACHE();
+///     assert_eq!(CACHE.read().len(), 3);
+/// }
+/// ```
+///
 /// # Notes
 ///
 /// This macro uses `spin::Lazy` internally, which provides a mechanism for lazy
 /// initialization. The initialization expression is only evaluated when the static
 /// is first accessed, which can help with performance and initialization order issues.
+///
+/// A `mut static` is a `spin::Lazy<spin::RwLock<TYPE>>`, so `NAME.read()` and
+/// `NAME.write()` are just `spin::RwLock`'s own accessors; the macro additionally
+/// generates a `fn reset_NAME()` that re-runs `INITIALIZER` and overwrites the current
+/// value.
+///
+/// An `after(...)` dependency graph (`A after(B)`, `B after(A)`, or any longer cycle
+/// among statics declared in the same block) is rejected at compile time: each block
+/// generates a `const _: () = assert!(...);` that walks the dependency graph and fails
+/// to compile, with a message naming the problem, instead of deadlocking or recursing
+/// forever the first time an affected static is accessed.
+///
+/// This is the `std`-oriented definition of `rt_init!`, built on `spin::Lazy`. Enable the
+/// `no_std` feature to swap in a `spin::Once`-based definition with a reduced surface (see
+/// below) for environments where `spin::Lazy` isn't available.
+#[cfg(not(feature = "no_std"))]
 #[macro_export]
 macro_rules! rt_init {
-    ($($vis:vis static $name:ident: $type:ty = $init:expr;)+) => {
+    (preload($group:ident); $(#[$first_attr:meta])* $first_vis:vis static $first_name:ident $(after($($first_dep:ident),+ $(,)?))?: $first_type:ty = $first_init:expr; $($(#[$attr:meta])* $vis:vis static $name:ident $(after($($dep:ident),+ $(,)?))?: $type:ty = $init:expr;)*) => {
+        $crate::rt_init! {
+            $(#[$first_attr])* $first_vis static $first_name $(after($($first_dep),+))?: $first_type = $first_init;
+            $($(#[$attr])* $vis static $name $(after($($dep),+))?: $type = $init;)*
+        }
+
+        ::paste::paste! {
+            $first_vis fn [<preload_ $group>]() {
+                $(#[$first_attr])*
+                ::spin::Lazy::force(&$first_name);
+                $(
+                    $(#[$attr])*
+                    ::spin::Lazy::force(&$name);
+                )*
+            }
+        }
+    };
+    ($($(#[$attr:meta])* $vis:vis mut static $name:ident: $type:ty = $init:expr;)+) => {
+        $(
+            $(#[$attr])*
+            $vis static $name: ::spin::Lazy<::spin::RwLock<$type>> =
+                ::spin::Lazy::new(|| ::spin::RwLock::new($init));
+        )+
+
+        ::paste::paste! {
+            $(
+                $(#[$attr])*
+                #[allow(non_snake_case)]
+                $vis fn [<reset_ $name>]() {
+                    *$name.write() = $init;
+                }
+            )+
+        }
+    };
+    ($($(#[$attr:meta])* $vis:vis static $name:ident $(after($($dep:ident),+ $(,)?))?: $type:ty = $init:expr;)+) => {
+        const _: () = {
+            const __RT_INIT_NAMES: &[&str] = &[ $(stringify!($name)),+ ];
+            const __RT_INIT_DEPS: &[&[&str]] = &[
+                $( &[ $($(stringify!($dep)),+)? ] ),+
+            ];
+            assert!(
+                !$crate::__rt_init_has_after_cycle(__RT_INIT_NAMES, __RT_INIT_DEPS),
+                concat!(
+                    "rt_init!: cyclic `after(...)` dependency detected among: ",
+                    $(stringify!($name), ", "),+
+                ),
+            );
+        };
+
         $(
-            $vis static $name: ::spin::Lazy<$type> = ::spin::Lazy::new(|| $init);
+            $(#[$attr])*
+            $vis static $name: ::spin::Lazy<$type> = ::spin::Lazy::new(|| {
+                $($(
+                    ::spin::Lazy::force(&$dep);
+                )+)?
+                $init
+            });
         )*
     };
 }
 
+/// The `no_std` definition of `rt_init!`, built on `spin::Once` instead of `spin::Lazy`.
+///
+/// Enabled via the `no_std` feature, this mirrors the syntax of the `std` definition for
+/// attributes, visibility, and `NAME: TYPE = INITIALIZER;` (preload groups and `after(...)`
+/// dependencies are not supported here). Each declared static expands to a unique,
+/// zero-sized type whose `Deref<Target = TYPE>` impl keeps the value in a
+/// `spin::Once<TYPE>` static and calls `call_once` on first access. This avoids pulling in `spin::Lazy`, keeping the
+/// macro usable in `no_std` embedded and kernel environments.
+#[cfg(feature = "no_std")]
+#[macro_export]
+macro_rules! rt_init {
+    ($($(#[$attr:meta])* $vis:vis static $name:ident: $type:ty = $init:expr;)+) => {
+        ::paste::paste! {
+            $(
+                #[allow(non_camel_case_types)]
+                #[doc(hidden)]
+                $vis struct [<__RtInitOnce_ $name>];
+
+                impl ::core::ops::Deref for [<__RtInitOnce_ $name>] {
+                    type Target = $type;
+
+                    fn deref(&self) -> &$type {
+                        static __ONCE: ::spin::Once<$type> = ::spin::Once::INIT;
+                        __ONCE.call_once(|| $init)
+                    }
+                }
+
+                $(#[$attr])*
+                $vis static $name: [<__RtInitOnce_ $name>] = [<__RtInitOnce_ $name>];
+            )+
+        }
+    };
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -115,4 +386,109 @@ mod tests {
 
         assert_eq!(*COMPLEX_STATIC, vec![0, 2, 4, 6, 8]);
     }
+
+    #[test]
+    fn test_attributes_and_doc_comments() {
+        rt_init! {
+            /// A documented static with a doc comment.
+            #[allow(dead_code)]
+            static DOCUMENTED_STATIC: u32 = 7;
+
+            #[cfg(test)]
+            static CFG_STATIC: u32 = 9;
+        }
+
+        assert_eq!(*DOCUMENTED_STATIC, 7);
+        assert_eq!(*CFG_STATIC, 9);
+    }
+
+    #[cfg(not(feature = "no_std"))]
+    #[test]
+    fn test_preload_group() {
+        rt_init! {
+            preload(demo);
+            static PRELOAD_STATIC1: u32 = 11;
+            static PRELOAD_STATIC2: u32 = 22;
+        }
+
+        preload_demo();
+
+        assert_eq!(*PRELOAD_STATIC1, 11);
+        assert_eq!(*PRELOAD_STATIC2, 22);
+    }
+
+    #[cfg(not(feature = "no_std"))]
+    #[test]
+    fn test_preload_group_respects_attrs() {
+        rt_init! {
+            preload(attrs_demo);
+            #[cfg(not(test))]
+            static GATED_OUT: u32 = 1;
+            #[cfg(test)]
+            static GATED_IN: u32 = 2;
+            static UNGATED: u32 = 3;
+        }
+
+        preload_attrs_demo();
+
+        assert_eq!(*GATED_IN, 2);
+        assert_eq!(*UNGATED, 3);
+    }
+
+    #[cfg(not(feature = "no_std"))]
+    #[test]
+    fn test_after_dependency() {
+        rt_init! {
+            static BASE_VALUE: u64 = 10;
+            static DERIVED_VALUE after(BASE_VALUE): u64 = *BASE_VALUE * 2;
+        }
+
+        assert_eq!(*DERIVED_VALUE, 20);
+        assert_eq!(*BASE_VALUE, 10);
+    }
+
+    #[cfg(feature = "no_std")]
+    #[test]
+    fn test_no_std_once_based_static() {
+        rt_init! {
+            static NO_STD_STATIC: u32 = 5;
+        }
+
+        assert_eq!(*NO_STD_STATIC, 5);
+    }
+
+    #[cfg(not(feature = "no_std"))]
+    #[test]
+    fn test_mut_static_read_write_reset() {
+        rt_init! {
+            mut static COUNTER: u64 = 0;
+        }
+
+        assert_eq!(*COUNTER.read(), 0);
+
+        *COUNTER.write() += 1;
+        assert_eq!(*COUNTER.read(), 1);
+
+        reset_COUNTER();
+        assert_eq!(*COUNTER.read(), 0);
+    }
+
+    #[cfg(not(feature = "no_std"))]
+    #[test]
+    fn test_mut_static_respects_attrs() {
+        rt_init! {
+            #[cfg(not(test))]
+            mut static GATED_OUT: u32 = 1;
+            #[cfg(test)]
+            mut static GATED_IN: u32 = 2;
+        }
+
+        assert_eq!(*GATED_IN.read(), 2);
+
+        *GATED_IN.write() = 5;
+        assert_eq!(*GATED_IN.read(), 5);
+
+        reset_GATED_IN();
+        assert_eq!(*GATED_IN.read(), 2);
+    }
 }